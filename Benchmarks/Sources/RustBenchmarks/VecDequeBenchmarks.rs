@@ -2,6 +2,16 @@ use std::alloc::{alloc, dealloc, Layout};
 use std::collections::VecDeque;
 use std::hint::black_box;
 
+/// A non-trivial, fixed-size element payload used to measure the bulk
+/// element-move cost of the ring buffer, which is invisible with word-sized
+/// `isize` elements. The layout is `#[repr(C)]` so the Swift side can hand us
+/// a matching 64-byte struct buffer.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Blob {
+  bytes: [u8; 64],
+}
+
 #[no_mangle]
 unsafe extern "C" fn rust_vecdeque_create(
   mut start: *const isize,
@@ -128,6 +138,224 @@ unsafe extern "C" fn rust_vecdeque_random_insertions(
   black_box(&deque);
 }
 
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_create_blob(
+  mut start: *const Blob,
+  count: isize
+) -> *mut VecDeque<Blob> {
+  let layout = Layout::new::<VecDeque<Blob>>();
+  let allocated = alloc(layout) as *mut VecDeque<Blob>;
+
+  let mut vec_deque = VecDeque::with_capacity(count as usize);
+
+  for _ in 0..count {
+    vec_deque.push_back(start.read());
+    start = start.add(1);
+  }
+
+  allocated.write(vec_deque);
+
+  allocated
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_destroy_blob(ptr: *mut VecDeque<Blob>) {
+  ptr.drop_in_place();
+  dealloc(ptr as *mut u8, Layout::new::<VecDeque<Blob>>());
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_iterate_blob(ptr: *mut u8) {
+  let deque_ptr = ptr as *mut VecDeque<Blob>;
+
+  for e in &*deque_ptr {
+    black_box(e);
+  }
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_random_insertions_blob(
+  start: *const Blob,
+  count: isize
+) {
+  let mut deque = VecDeque::new();
+
+  for i in 0..count {
+    deque.insert(i as usize, start.wrapping_add(i as usize).read());
+  }
+
+  black_box(&deque);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_try_reserve_then_fill(
+  mut start: *const isize,
+  count: isize
+) {
+  let mut vd = VecDeque::new();
+
+  let result = vd.try_reserve(count as usize);
+  black_box(&result);
+
+  for _ in 0..count {
+    vd.push_back(start.read());
+    start = start.add(1);
+  }
+
+  black_box(&vd);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_drain_middle(
+  mut start: *const isize,
+  count: isize
+) {
+  let mut vd = VecDeque::with_capacity(count as usize);
+
+  for _ in 0..count {
+    vd.push_back(start.read());
+    start = start.add(1);
+  }
+
+  let lo = count as usize / 4;
+  let hi = 3 * count as usize / 4;
+  for e in vd.drain(lo..hi) {
+    black_box(e);
+  }
+
+  black_box(&vd);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_random_removals(
+  mut start: *const isize,
+  count: isize
+) {
+  let mut vd = VecDeque::with_capacity(count as usize);
+
+  for _ in 0..count {
+    vd.push_back(start.read());
+    start = start.add(1);
+  }
+
+  // Reproducible interior indices from an LCG seeded off `count`.
+  let mut state = count as u64;
+  while !vd.is_empty() {
+    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    let index = (state >> 33) as usize % vd.len();
+    black_box(vd.remove(index));
+  }
+
+  black_box(&vd);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_random_swap_removals(
+  mut start: *const isize,
+  count: isize
+) {
+  let mut vd = VecDeque::with_capacity(count as usize);
+
+  for _ in 0..count {
+    vd.push_back(start.read());
+    start = start.add(1);
+  }
+
+  let mut state = count as u64;
+  while !vd.is_empty() {
+    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    let index = (state >> 33) as usize % vd.len();
+    black_box(vd.swap_remove_back(index));
+  }
+
+  black_box(&vd);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_rotate_left(
+  mut start: *const isize,
+  count: isize,
+  mid: isize
+) {
+  let mut vd = VecDeque::with_capacity(count as usize);
+
+  for _ in 0..count {
+    vd.push_back(start.read());
+    start = start.add(1);
+  }
+
+  vd.rotate_left(mid as usize);
+
+  black_box(&vd);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_rotate_right(
+  mut start: *const isize,
+  count: isize,
+  mid: isize
+) {
+  let mut vd = VecDeque::with_capacity(count as usize);
+
+  for _ in 0..count {
+    vd.push_back(start.read());
+    start = start.add(1);
+  }
+
+  vd.rotate_right(mid as usize);
+
+  black_box(&vd);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_extend_from_slice(
+  start: *const isize,
+  count: isize
+) {
+  let slice = std::slice::from_raw_parts(start, count as usize);
+
+  let vd = VecDeque::from_iter(slice.iter().copied());
+
+  black_box(&vd);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_extend_reserved(
+  start: *const isize,
+  count: isize
+) {
+  let slice = std::slice::from_raw_parts(start, count as usize);
+
+  let mut vd = VecDeque::new();
+  vd.reserve(count as usize);
+  vd.extend(slice.iter().copied());
+
+  black_box(&vd);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rust_vecdeque_make_contiguous(
+  mut start: *const isize,
+  count: isize
+) {
+  let mut vd = VecDeque::with_capacity(count as usize);
+
+  for _ in 0..count {
+    vd.push_back(start.read());
+    start = start.add(1);
+  }
+
+  // Force a worst-case wrapped layout: walk the logical head roughly to the
+  // middle of the allocation so `make_contiguous` has to perform its O(n)
+  // rotation rather than returning the slice for free.
+  for _ in 0..count / 2 {
+    let front = vd.pop_front().unwrap();
+    vd.push_back(front);
+  }
+
+  black_box(vd.make_contiguous());
+}
+
 #[no_mangle]
 unsafe extern "C" fn rust_vecdeque_iterate(ptr: *mut u8) {
   let deque_ptr = ptr as *mut VecDeque<isize>;